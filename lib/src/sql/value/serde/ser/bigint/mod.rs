@@ -16,12 +16,106 @@ use std::str::FromStr;
 
 pub(super) struct Serializer;
 
-#[derive(Clone, Debug, Copy, Default, PartialEq, Eq, Hash)]
-pub struct BiggerInt(I512);
+/// A 512-bit signed integer.
+///
+/// Most values seen in practice fit comfortably in an `i128`, so the
+/// `Small` arm avoids paying for a 512-bit representation (and 512-bit
+/// arithmetic) in the common case. `Big` only holds values whose magnitude
+/// exceeds `i128`'s range.
+///
+/// Invariant: any value representable as an `i128` is always stored as
+/// `Small`; `Big` is only ever used once that range is exceeded. Every
+/// constructor and arithmetic operation re-normalizes its result to
+/// preserve this, so `PartialEq`/`Eq`/`Hash` can be derived directly -
+/// equal values are always stored in the same arm.
+#[derive(Clone, Debug, Copy, PartialEq, Eq, Hash)]
+pub enum BiggerInt {
+	Small(i128),
+	Big(I512),
+}
+
+impl Default for BiggerInt {
+	fn default() -> Self {
+		BiggerInt::Small(0)
+	}
+}
+
+impl BiggerInt {
+	/// Converts to the full-width representation, regardless of arm.
+	fn to_i512(self) -> I512 {
+		match self {
+			BiggerInt::Small(v) => i128_to_i512(v),
+			BiggerInt::Big(v) => v,
+		}
+	}
+
+	/// Builds a canonical `BiggerInt` from a full-width value, demoting to
+	/// `Small` when it fits.
+	fn from_i512(v: I512) -> Self {
+		if v.le(MAX_I128) && v.ge(MIN_I128) {
+			BiggerInt::Small(i512_to_i128(v))
+		} else {
+			BiggerInt::Big(v)
+		}
+	}
+}
+
+fn i128_to_i512(v: i128) -> I512 {
+	I512::from_str(v.to_string().as_str()).unwrap()
+}
+
+/// The low two little-endian `u64` limbs of `v`, read out explicitly rather
+/// than reinterpreting the limb array's native in-memory layout - the same
+/// portability concern `to_le_bytes_64` addresses for the on-wire format.
+fn low_128_le_limbs(v: I512) -> (u64, u64) {
+	let bits = v.to_bits();
+	let digits = bits.digits();
+	(digits[0], digits[1])
+}
+
+/// Reconstructs an `i128` from an `I512` already known to be within
+/// `i128`'s range.
+fn i512_to_i128(v: I512) -> i128 {
+	let (lo, hi) = low_128_le_limbs(v);
+	(((hi as u128) << 64) | lo as u128) as i128
+}
+
+/// Nearest `f64` to an `I512`. The 64 most-significant bits of the
+/// magnitude are rounded once, with any lower set bit folded into bit 0 so
+/// that ties are broken correctly. Every `I512` magnitude is below
+/// `f64::MAX`, so the result is always finite.
+fn i512_to_f64(v: I512) -> f64 {
+	if v.is_zero() {
+		return 0.0;
+	}
+	let negative = v.is_negative();
+	let abs = v.abs();
+	let bits = abs.to_bits();
+	let digits = bits.digits();
+	// Index of the most-significant non-zero limb.
+	let top = digits.iter().rposition(|&d| d != 0).unwrap_or(0);
+	let result = if top == 0 {
+		digits[0] as f64
+	} else {
+		let lz = digits[top].leading_zeros();
+		let window = if lz == 0 {
+			digits[top]
+		} else {
+			(digits[top] << lz) | (digits[top - 1] >> (64 - lz))
+		};
+		let sticky = digits[top - 1] << lz != 0 || digits[..top - 1].iter().any(|&d| d != 0);
+		(window | sticky as u64) as f64 * 2f64.powi(64 * top as i32 - lz as i32)
+	};
+	if negative {
+		-result
+	} else {
+		result
+	}
+}
 
 impl Serialize for BiggerInt {
 	fn serialize<S: SerdeSerializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-		let mut hex = self.0.to_str_radix(16);
+		let mut hex = self.to_i512().to_str_radix(16);
 		if hex.starts_with('-') {
 			hex = "-0x".to_owned() + &hex[1..];
 		} else {
@@ -54,18 +148,245 @@ impl<'de> Visitor<'de> for BiggerIntVisitor {
 		E: de::Error,
 	{
 		match I512::from_str_radix(v, 16) {
-			Ok(v) => Ok(BiggerInt(v)),
+			Ok(v) => Ok(BiggerInt::from_i512(v)),
 			Err(_) => Err(de::Error::custom("BiggerInt")),
 		}
 	}
 }
 
+/// Alternative wire representations of [`BiggerInt`], for use with
+/// `#[serde(with = "...")]` on fields that need a form other than the
+/// default `0x`-prefixed hex string.
+pub mod repr {
+	/// Plain base-10 string, e.g. `"42"` or `"-42"`.
+	pub mod decimal {
+		use super::super::BiggerInt;
+		use bnum::types::I512;
+		use serde::de::{self, Visitor};
+		use serde::{Deserializer, Serializer};
+		use std::fmt::Formatter;
+
+		pub fn serialize<S: Serializer>(value: &BiggerInt, serializer: S) -> Result<S::Ok, S::Error> {
+			serializer.serialize_str(&value.to_i512().to_str_radix(10))
+		}
+
+		pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<BiggerInt, D::Error> {
+			struct DecimalVisitor;
+
+			impl<'de> Visitor<'de> for DecimalVisitor {
+				type Value = BiggerInt;
+
+				fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+					formatter.write_str("a base-10 integer string")
+				}
+
+				fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+					I512::from_str_radix(v, 10).map(BiggerInt::from_i512).map_err(de::Error::custom)
+				}
+			}
+
+			deserializer.deserialize_str(DecimalVisitor)
+		}
+	}
+
+	/// Serializes as `0x`-prefixed hex, but deserializes either `0x`-prefixed
+	/// hex or a bare base-10 string.
+	pub mod prefixed {
+		use super::super::BiggerInt;
+		use bnum::types::I512;
+		use serde::de::{self, Visitor};
+		use serde::{Deserializer, Serialize, Serializer};
+		use std::fmt::Formatter;
+
+		pub fn serialize<S: Serializer>(value: &BiggerInt, serializer: S) -> Result<S::Ok, S::Error> {
+			Serialize::serialize(value, serializer)
+		}
+
+		pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<BiggerInt, D::Error> {
+			struct PrefixedVisitor;
+
+			impl<'de> Visitor<'de> for PrefixedVisitor {
+				type Value = BiggerInt;
+
+				fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+					formatter.write_str("a `0x`-prefixed hex string or a base-10 integer string")
+				}
+
+				fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+					let (digits, radix) = match v.strip_prefix("0x") {
+						Some(rest) => (rest, 16),
+						None => (v, 10),
+					};
+					I512::from_str_radix(digits, radix).map(BiggerInt::from_i512).map_err(de::Error::custom)
+				}
+			}
+
+			deserializer.deserialize_str(PrefixedVisitor)
+		}
+	}
+
+	/// Deserializes any of `-0x…`/`0x…` hex, a bare base-10 string, or a raw
+	/// JSON/CBOR integer. Serializes the same way as the default `0x…` form.
+	pub mod permissive {
+		use super::super::BiggerInt;
+		use bnum::types::I512;
+		use serde::de::{self, Visitor};
+		use serde::{Deserializer, Serialize, Serializer};
+		use std::fmt::Formatter;
+
+		pub fn serialize<S: Serializer>(value: &BiggerInt, serializer: S) -> Result<S::Ok, S::Error> {
+			Serialize::serialize(value, serializer)
+		}
+
+		pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<BiggerInt, D::Error> {
+			struct PermissiveVisitor;
+
+			impl<'de> Visitor<'de> for PermissiveVisitor {
+				type Value = BiggerInt;
+
+				fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+					formatter.write_str("a hex string, a decimal string, or an integer")
+				}
+
+				fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+					let (digits, radix) = match v.strip_prefix("0x").or_else(|| v.strip_prefix("-0x")) {
+						Some(rest) => (rest, 16),
+						None => (v.strip_prefix('-').unwrap_or(v), 10),
+					};
+					let negative = v.starts_with('-');
+					let parsed = I512::from_str_radix(digits, radix).map_err(de::Error::custom)?;
+					Ok(BiggerInt::from_i512(if negative {
+						-parsed
+					} else {
+						parsed
+					}))
+				}
+
+				fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+					Ok(BiggerInt::from(v))
+				}
+
+				fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+					Ok(BiggerInt::from(v))
+				}
+
+				fn visit_i128<E: de::Error>(self, v: i128) -> Result<Self::Value, E> {
+					Ok(BiggerInt::from(v))
+				}
+
+				fn visit_u128<E: de::Error>(self, v: u128) -> Result<Self::Value, E> {
+					Ok(BiggerInt::from(v))
+				}
+			}
+
+			deserializer.deserialize_any(PermissiveVisitor)
+		}
+	}
+
+	/// Fixed 64-byte two's-complement arrays, sign-extended to the full
+	/// width of the underlying [`I512`].
+	pub mod bytes {
+		use super::super::BiggerInt;
+		use bnum::types::I512;
+		use serde::de::{self, SeqAccess, Visitor};
+		use serde::{Deserializer, Serializer};
+		use std::fmt::Formatter;
+
+		fn to_le_bytes(value: &I512) -> [u8; 64] {
+			let bits = value.to_bits();
+			let digits = bits.digits();
+			let mut out = [0u8; 64];
+			for (limb, chunk) in digits.iter().zip(out.chunks_exact_mut(8)) {
+				chunk.copy_from_slice(&limb.to_le_bytes());
+			}
+			out
+		}
+
+		fn from_le_bytes(bytes: &[u8; 64]) -> Option<I512> {
+			I512::from_le_slice(bytes)
+		}
+
+		struct BytesVisitor;
+
+		impl<'de> Visitor<'de> for BytesVisitor {
+			type Value = [u8; 64];
+
+			fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+				formatter.write_str("64 bytes")
+			}
+
+			fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+				v.try_into().map_err(|_| de::Error::custom("expected 64 bytes"))
+			}
+
+			fn visit_byte_buf<E: de::Error>(self, v: Vec<u8>) -> Result<Self::Value, E> {
+				self.visit_bytes(&v)
+			}
+
+			// Self-describing formats (e.g. JSON) don't have a distinct
+			// bytes type and deserialize what `serialize_bytes` wrote as a
+			// plain sequence instead.
+			fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+			where
+				A: SeqAccess<'de>,
+			{
+				let mut bytes = [0u8; 64];
+				for (i, b) in bytes.iter_mut().enumerate() {
+					*b = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(i, &self))?;
+				}
+				Ok(bytes)
+			}
+		}
+
+		/// Little-endian fixed 64-byte two's-complement representation.
+		pub mod le {
+			use super::{from_le_bytes, to_le_bytes, BytesVisitor};
+			use super::super::super::BiggerInt;
+			use serde::{Deserializer, Serializer};
+
+			pub fn serialize<S: Serializer>(value: &BiggerInt, serializer: S) -> Result<S::Ok, S::Error> {
+				serializer.serialize_bytes(&to_le_bytes(&value.to_i512()))
+			}
+
+			pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<BiggerInt, D::Error> {
+				use serde::de::Error as _;
+				let bytes = deserializer.deserialize_bytes(BytesVisitor)?;
+				from_le_bytes(&bytes)
+					.map(BiggerInt::from_i512)
+					.ok_or_else(|| D::Error::custom("invalid BiggerInt bytes"))
+			}
+		}
+
+		/// Big-endian fixed 64-byte two's-complement representation.
+		pub mod be {
+			use super::{from_le_bytes, to_le_bytes, BytesVisitor};
+			use super::super::super::BiggerInt;
+			use serde::{Deserializer, Serializer};
+
+			pub fn serialize<S: Serializer>(value: &BiggerInt, serializer: S) -> Result<S::Ok, S::Error> {
+				let mut le = to_le_bytes(&value.to_i512());
+				le.reverse();
+				serializer.serialize_bytes(&le)
+			}
+
+			pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<BiggerInt, D::Error> {
+				use serde::de::Error as _;
+				let mut bytes = deserializer.deserialize_bytes(BytesVisitor)?;
+				bytes.reverse();
+				from_le_bytes(&bytes)
+					.map(BiggerInt::from_i512)
+					.ok_or_else(|| D::Error::custom("invalid BiggerInt bytes"))
+			}
+		}
+	}
+}
+
 macro_rules! impl_prim_conversions {
 	($($int: ty),*) => {
 		$(
 			impl From<$int> for BiggerInt {
 				fn from(i: $int) -> Self {
-					Self(I512::try_from(i).unwrap())
+					Self::Small(i as i128)
 				}
 			}
 		)*
@@ -76,44 +397,102 @@ impl_prim_conversions!(i8, i16, i32, i64, isize, u8, u16, u32, u64);
 
 impl From<I512> for BiggerInt {
 	fn from(v: I512) -> Self {
-		Self(v)
+		Self::from_i512(v)
 	}
 }
 
 impl From<usize> for BiggerInt {
 	fn from(v: usize) -> Self {
-		Self(I512::from_str(v.to_string().as_str()).unwrap())
+		Self::Small(v as i128)
 	}
 }
 
 impl From<i128> for BiggerInt {
 	fn from(v: i128) -> Self {
-		Self(I512::from_str(v.to_string().as_str()).unwrap())
+		Self::Small(v)
 	}
 }
 
 impl From<u128> for BiggerInt {
 	fn from(v: u128) -> Self {
-		Self(I512::from_str(v.to_string().as_str()).unwrap())
+		match i128::try_from(v) {
+			Ok(v) => Self::Small(v),
+			Err(_) => Self::Big(I512::from_str(v.to_string().as_str()).unwrap()),
+		}
+	}
+}
+
+/// Decomposes a finite, integral `f64` into the exact `I512` it represents,
+/// by shifting its mantissa by its (possibly negative) binary exponent.
+fn f64_to_i512(v: f64) -> Option<I512> {
+	if !v.is_finite() || v.fract() != 0.0 {
+		return None;
+	}
+	if v == 0.0 {
+		return Some(I512::ZERO);
+	}
+
+	let bits = v.to_bits();
+	let negative = bits >> 63 != 0;
+	let biased_exponent = ((bits >> 52) & 0x7ff) as i32;
+	let mantissa = if biased_exponent == 0 {
+		(bits & 0xf_ffff_ffff_ffff) << 1
+	} else {
+		(bits & 0xf_ffff_ffff_ffff) | 0x10_0000_0000_0000
+	};
+	// Unbiases the exponent and accounts for the mantissa already being
+	// shifted into an integer (rather than a 0.mmm fraction).
+	let exponent = biased_exponent - 1075;
+
+	let mut result = I512::from(mantissa);
+	if exponent >= 0 {
+		let exponent = exponent as u32;
+		// `checked_shl` only rejects shift amounts that exceed I512's bit
+		// width - it doesn't notice the *result* overflowing when the shift
+		// itself is in range. A signed 512-bit integer can only hold a
+		// non-negative magnitude in its low 511 bits (bit 511 is the sign
+		// bit), so reject upfront whenever the shifted mantissa would need
+		// more than that.
+		let mantissa_bits = 64 - mantissa.leading_zeros();
+		if mantissa_bits + exponent > 511 {
+			return None;
+		}
+		result = result.checked_shl(exponent)?;
+	} else {
+		let divisor = I512::ONE.checked_shl((-exponent) as u32)?;
+		if !(result % divisor).is_zero() {
+			// `v.fract() == 0.0` guarantees this divides evenly.
+			return None;
+		}
+		result /= divisor;
 	}
+
+	if negative {
+		result = -result;
+	}
+	Some(result)
 }
 
 impl TryFrom<f64> for BiggerInt {
-	// todo: [zyre] add support for f64
 	type Error = Error;
 	fn try_from(v: f64) -> Result<Self, Self::Error> {
-		Err(Error::TryFrom(v.to_string(), "BiggerInt"))
+		f64_to_i512(v).map(BiggerInt::from_i512).ok_or_else(|| Error::TryFrom(v.to_string(), "BiggerInt"))
 	}
 }
 
 impl TryFrom<Decimal> for BiggerInt {
-	// todo: [zyre] properly handle conversions
 	type Error = Error;
 	fn try_from(v: Decimal) -> Result<Self, Self::Error> {
-		match v.to_i128() {
-			Some(v) => Ok(BiggerInt::from(v)),
-			None => Err(Error::TryFrom(v.to_string(), "BiggerInt")),
+		let mantissa = v.mantissa();
+		let scale = v.scale();
+		if scale == 0 {
+			return Ok(BiggerInt::from(mantissa));
+		}
+		let divisor = 10i128.pow(scale);
+		if mantissa % divisor != 0 {
+			return Err(Error::TryFrom(v.to_string(), "BiggerInt"));
 		}
+		Ok(BiggerInt::from(mantissa / divisor))
 	}
 }
 
@@ -171,123 +550,105 @@ const MAX_U128: &I512 = &I512::parse_str_radix("34028236692093846346337460743176
 
 impl BiggerInt {
 	// Satisfy `try_into_prim` macro
+	//
+	// Every value within the `i8`..=`i128`/`u8`..=`u64` ranges is, by the
+	// `Small`/`Big` invariant, always stored as `Small` - so the `Big` arm
+	// can never satisfy these and short-circuits to `None`.
 	#[inline]
 	pub fn to_i8(self) -> Option<i8> {
-		if self.0.le(MAX_I8) && self.0.ge(MIN_I8) {
-			let bits = self.0.to_bits();
-			let casted: &[i8] = bytemuck::cast_slice(bits.digits());
-			Option::from(casted[0])
-		} else {
-			None
+		match self {
+			BiggerInt::Small(v) => i8::try_from(v).ok(),
+			BiggerInt::Big(_) => None,
 		}
 	}
 	#[inline]
 	pub fn to_i16(self) -> Option<i16> {
-		if self.0.le(MAX_I16) && self.0.ge(MIN_I16) {
-			let bits = self.0.to_bits();
-			let casted: &[i16] = bytemuck::cast_slice(bits.digits());
-			Option::from(casted[0])
-		} else {
-			None
+		match self {
+			BiggerInt::Small(v) => i16::try_from(v).ok(),
+			BiggerInt::Big(_) => None,
 		}
 	}
 	#[inline]
 	pub fn to_i32(self) -> Option<i32> {
-		if self.0.le(MAX_I32) && self.0.ge(MIN_I32) {
-			let bits = self.0.to_bits();
-			let casted: &[i32] = bytemuck::cast_slice(bits.digits());
-			Option::from(casted[0])
-		} else {
-			None
+		match self {
+			BiggerInt::Small(v) => i32::try_from(v).ok(),
+			BiggerInt::Big(_) => None,
 		}
 	}
 	#[inline]
 	pub fn to_i64(self) -> Option<i64> {
-		if self.0.le(MAX_I64) && self.0.ge(MIN_I64) {
-			let bits = self.0.to_bits();
-			let casted: &[i64] = bytemuck::cast_slice(bits.digits());
-			Option::from(casted[0])
-		} else {
-			None
+		match self {
+			BiggerInt::Small(v) => i64::try_from(v).ok(),
+			BiggerInt::Big(_) => None,
 		}
 	}
 	#[inline]
 	pub fn to_i128(self) -> Option<i128> {
-		if self.0.le(MAX_I128) && self.0.ge(MIN_I128) {
-			let bits = self.0.to_bits();
-			let casted: &[i128] = bytemuck::cast_slice(bits.digits());
-			Option::from(casted[0])
-		} else {
-			None
+		match self {
+			BiggerInt::Small(v) => Some(v),
+			BiggerInt::Big(_) => None,
 		}
 	}
 	#[inline]
 	pub fn to_u8(self) -> Option<u8> {
-		if self.0.le(MAX_U8) {
-			let bits = self.0.to_bits();
-			let casted: &[u8] = bytemuck::cast_slice(bits.digits());
-			Option::from(casted[0])
-		} else {
-			None
+		match self {
+			BiggerInt::Small(v) => u8::try_from(v).ok(),
+			BiggerInt::Big(_) => None,
 		}
 	}
 	#[inline]
 	pub fn to_u16(self) -> Option<u16> {
-		if self.0.le(MAX_U16) {
-			let bits = self.0.to_bits();
-			let casted: &[u16] = bytemuck::cast_slice(bits.digits());
-			Option::from(casted[0])
-		} else {
-			None
+		match self {
+			BiggerInt::Small(v) => u16::try_from(v).ok(),
+			BiggerInt::Big(_) => None,
 		}
 	}
 	#[inline]
 	pub fn to_u32(self) -> Option<u32> {
-		if self.0.le(MAX_U32) {
-			let bits = self.0.to_bits();
-			let casted: &[u32] = bytemuck::cast_slice(bits.digits());
-			Option::from(casted[0])
-		} else {
-			None
+		match self {
+			BiggerInt::Small(v) => u32::try_from(v).ok(),
+			BiggerInt::Big(_) => None,
 		}
 	}
 	#[inline]
 	pub fn to_u64(self) -> Option<u64> {
-		if self.0.le(MAX_U64) {
-			let bits = self.0.to_bits();
-			let casted: &[u64] = bytemuck::cast_slice(bits.digits());
-			Option::from(casted[0])
-		} else {
-			None
+		match self {
+			BiggerInt::Small(v) => u64::try_from(v).ok(),
+			BiggerInt::Big(_) => None,
 		}
 	}
 	#[inline]
 	pub fn to_u128(self) -> Option<u128> {
-		if self.0.le(MAX_U128) {
-			let bits = self.0.to_bits();
-			let casted: &[u128] = bytemuck::cast_slice(bits.digits());
-			Option::from(casted[0])
-		} else {
-			None
+		match self {
+			BiggerInt::Small(v) => u128::try_from(v).ok(),
+			BiggerInt::Big(v) => {
+				if v.le(MAX_U128) && v.is_positive() {
+					let (lo, hi) = low_128_le_limbs(v);
+					Some(((hi as u128) << 64) | lo as u128)
+				} else {
+					None
+				}
+			}
 		}
 	}
 	#[inline]
 	pub fn to_f32(self) -> Option<f32> {
-		let bits = self.0.to_bits();
-		let casted: &[f32] = bytemuck::cast_slice(bits.digits());
-		Option::from(casted[0])
+		// `as` saturates to infinity past `f32::MAX`.
+		self.to_f64().map(|v| v as f32)
 	}
 	#[inline]
 	pub fn to_f64(self) -> Option<f64> {
-		let bits = self.0.to_bits();
-		let casted: &[f64] = bytemuck::cast_slice(bits.digits());
-		Option::from(casted[0])
+		match self {
+			BiggerInt::Small(v) => Some(v as f64),
+			BiggerInt::Big(v) => Some(i512_to_f64(v)),
+		}
 	}
 	#[inline]
 	pub fn to_usize(self) -> Option<usize> {
-		let bits = self.0.to_bits();
-		let casted: &[usize] = bytemuck::cast_slice(bits.digits());
-		Option::from(casted[0])
+		match self {
+			BiggerInt::Small(v) => usize::try_from(v).ok(),
+			BiggerInt::Big(_) => None,
+		}
 	}
 
 	pub fn from_str(s: &str) -> Result<Self, bnum::errors::ParseIntError> {
@@ -298,74 +659,132 @@ impl BiggerInt {
 			sval = &sval[2..];
 		}
 		let v = I512::from_str_radix(sval, 16)?;
-		Ok(BiggerInt(v))
+		Ok(BiggerInt::from_i512(v))
 	}
 
 	// Forward arithmetic operations
 	#[inline]
 	pub fn is_zero(&self) -> bool {
-		self.0.is_zero()
+		match self {
+			BiggerInt::Small(v) => *v == 0,
+			BiggerInt::Big(v) => v.is_zero(),
+		}
 	}
 	#[inline]
 	pub fn is_negative(&self) -> bool {
-		self.0.is_negative()
+		match self {
+			BiggerInt::Small(v) => *v < 0,
+			BiggerInt::Big(v) => v.is_negative(),
+		}
 	}
 	#[inline]
 	pub fn is_positive(&self) -> bool {
-		self.0.is_positive()
+		match self {
+			BiggerInt::Small(v) => *v > 0,
+			BiggerInt::Big(v) => v.is_positive(),
+		}
 	}
 	#[inline]
 	pub fn abs(&self) -> Self {
-		BiggerInt(self.0.abs())
+		match self {
+			BiggerInt::Small(v) => match v.checked_abs() {
+				Some(v) => BiggerInt::Small(v),
+				None => BiggerInt::from_i512(i128_to_i512(*v).abs()),
+			},
+			BiggerInt::Big(v) => BiggerInt::from_i512(v.abs()),
+		}
 	}
 	#[inline]
 	pub fn pow(&self, exp: u32) -> Self {
-		BiggerInt(self.0.pow(exp))
+		match self {
+			BiggerInt::Small(v) => match v.checked_pow(exp) {
+				Some(v) => BiggerInt::Small(v),
+				None => BiggerInt::from_i512(i128_to_i512(*v).pow(exp)),
+			},
+			BiggerInt::Big(v) => BiggerInt::from_i512(v.pow(exp)),
+		}
 	}
 	#[inline]
 	pub fn cmp(&self, other: Self) -> std::cmp::Ordering {
-		self.0.cmp(&other.0)
+		match (self, &other) {
+			(BiggerInt::Small(a), BiggerInt::Small(b)) => a.cmp(b),
+			_ => self.to_i512().cmp(&other.to_i512()),
+		}
 	}
 	#[inline]
 	pub fn eq(&self, other: &Self) -> bool {
-		self.0.eq(&other.0)
+		match (self, other) {
+			(BiggerInt::Small(a), BiggerInt::Small(b)) => a.eq(b),
+			(BiggerInt::Big(a), BiggerInt::Big(b)) => a.eq(b),
+			_ => false,
+		}
 	}
 	#[inline]
 	pub fn is_zero_or_positive(&self) -> bool {
-		self.0.is_zero() || self.0.is_positive()
+		self.is_zero() || self.is_positive()
 	}
 	#[inline]
 	pub fn is_zero_or_negative(&self) -> bool {
-		self.0.is_zero() || self.0.is_negative()
+		self.is_zero() || self.is_negative()
 	}
 	#[inline]
 	pub fn zero() -> Self {
-		BiggerInt(I512::ZERO)
+		BiggerInt::Small(0)
 	}
 	#[inline]
 	pub fn one() -> Self {
-		BiggerInt(I512::ONE)
+		BiggerInt::Small(1)
 	}
 
 	// checked arithmetic
 	pub fn checked_add(self, rhs: Self) -> Option<Self> {
-		self.0.checked_add(rhs.0).map(BiggerInt)
+		match (self, rhs) {
+			(BiggerInt::Small(a), BiggerInt::Small(b)) => match a.checked_add(b) {
+				Some(v) => Some(BiggerInt::Small(v)),
+				None => i128_to_i512(a).checked_add(i128_to_i512(b)).map(BiggerInt::from_i512),
+			},
+			(a, b) => a.to_i512().checked_add(b.to_i512()).map(BiggerInt::from_i512),
+		}
 	}
 
 	pub fn checked_sub(self, rhs: Self) -> Option<Self> {
-		self.0.checked_sub(rhs.0).map(BiggerInt)
+		match (self, rhs) {
+			(BiggerInt::Small(a), BiggerInt::Small(b)) => match a.checked_sub(b) {
+				Some(v) => Some(BiggerInt::Small(v)),
+				None => i128_to_i512(a).checked_sub(i128_to_i512(b)).map(BiggerInt::from_i512),
+			},
+			(a, b) => a.to_i512().checked_sub(b.to_i512()).map(BiggerInt::from_i512),
+		}
 	}
 
 	pub fn checked_mul(self, rhs: Self) -> Option<Self> {
-		self.0.checked_mul(rhs.0).map(BiggerInt)
+		match (self, rhs) {
+			(BiggerInt::Small(a), BiggerInt::Small(b)) => match a.checked_mul(b) {
+				Some(v) => Some(BiggerInt::Small(v)),
+				None => i128_to_i512(a).checked_mul(i128_to_i512(b)).map(BiggerInt::from_i512),
+			},
+			(a, b) => a.to_i512().checked_mul(b.to_i512()).map(BiggerInt::from_i512),
+		}
 	}
 
 	pub fn checked_div(self, rhs: Self) -> Option<Self> {
-		self.0.checked_div(rhs.0).map(BiggerInt)
+		match (self, rhs) {
+			(BiggerInt::Small(a), BiggerInt::Small(b)) => match a.checked_div(b) {
+				Some(v) => Some(BiggerInt::Small(v)),
+				None => i128_to_i512(a).checked_div(i128_to_i512(b)).map(BiggerInt::from_i512),
+			},
+			(a, b) => a.to_i512().checked_div(b.to_i512()).map(BiggerInt::from_i512),
+		}
 	}
 
 	pub fn checked_rem(self, rhs: Self) -> Option<Self> {
-		self.0.checked_rem(rhs.0).map(BiggerInt)
+		match (self, rhs) {
+			(BiggerInt::Small(a), BiggerInt::Small(b)) => match a.checked_rem(b) {
+				Some(v) => Some(BiggerInt::Small(v)),
+				None => i128_to_i512(a).checked_rem(i128_to_i512(b)).map(BiggerInt::from_i512),
+			},
+			(a, b) => a.to_i512().checked_rem(b.to_i512()).map(BiggerInt::from_i512),
+		}
 	}
 }
 
@@ -373,7 +792,12 @@ impl Neg for BiggerInt {
 	type Output = Self;
 	#[inline]
 	fn neg(self) -> Self {
-		self.0.overflowing_neg().0.into()
+		if let BiggerInt::Small(v) = self {
+			if let Some(v) = v.checked_neg() {
+				return BiggerInt::Small(v);
+			}
+		}
+		self.to_i512().overflowing_neg().0.into()
 	}
 }
 
@@ -381,7 +805,12 @@ impl Add<Self> for BiggerInt {
 	type Output = Self;
 	#[inline]
 	fn add(self, rhs: Self) -> Self {
-		self.0.overflowing_add(rhs.0).0.into()
+		if let (BiggerInt::Small(a), BiggerInt::Small(b)) = (self, rhs) {
+			if let Some(v) = a.checked_add(b) {
+				return BiggerInt::Small(v);
+			}
+		}
+		self.to_i512().overflowing_add(rhs.to_i512()).0.into()
 	}
 }
 
@@ -389,7 +818,7 @@ impl<'a, 'b> Add<&'b BiggerInt> for &'a BiggerInt {
 	type Output = BiggerInt;
 	#[inline]
 	fn add(self, rhs: &'b BiggerInt) -> BiggerInt {
-		self.0.overflowing_add(rhs.0).0.into()
+		*self + *rhs
 	}
 }
 
@@ -397,7 +826,12 @@ impl Sub<Self> for BiggerInt {
 	type Output = Self;
 	#[inline]
 	fn sub(self, rhs: Self) -> Self {
-		self.0.overflowing_sub(rhs.0).0.into()
+		if let (BiggerInt::Small(a), BiggerInt::Small(b)) = (self, rhs) {
+			if let Some(v) = a.checked_sub(b) {
+				return BiggerInt::Small(v);
+			}
+		}
+		self.to_i512().overflowing_sub(rhs.to_i512()).0.into()
 	}
 }
 
@@ -405,7 +839,7 @@ impl<'a, 'b> Sub<&'b BiggerInt> for &'a BiggerInt {
 	type Output = BiggerInt;
 	#[inline]
 	fn sub(self, rhs: &'b BiggerInt) -> BiggerInt {
-		self.0.overflowing_sub(rhs.0).0.into()
+		*self - *rhs
 	}
 }
 
@@ -413,7 +847,12 @@ impl Mul<Self> for BiggerInt {
 	type Output = Self;
 	#[inline]
 	fn mul(self, rhs: Self) -> Self {
-		self.0.mul(rhs.0).into()
+		if let (BiggerInt::Small(a), BiggerInt::Small(b)) = (self, rhs) {
+			if let Some(v) = a.checked_mul(b) {
+				return BiggerInt::Small(v);
+			}
+		}
+		self.to_i512().mul(rhs.to_i512()).into()
 	}
 }
 
@@ -421,7 +860,7 @@ impl<'a, 'b> Mul<&'b BiggerInt> for &'a BiggerInt {
 	type Output = BiggerInt;
 	#[inline]
 	fn mul(self, rhs: &'b BiggerInt) -> BiggerInt {
-		self.0.mul(rhs.0).into()
+		*self * *rhs
 	}
 }
 
@@ -429,7 +868,12 @@ impl Div<Self> for BiggerInt {
 	type Output = Self;
 	#[inline]
 	fn div(self, rhs: Self) -> Self {
-		self.0.div(rhs.0).into()
+		if let (BiggerInt::Small(a), BiggerInt::Small(b)) = (self, rhs) {
+			if let Some(v) = a.checked_div(b) {
+				return BiggerInt::Small(v);
+			}
+		}
+		self.to_i512().div(rhs.to_i512()).into()
 	}
 }
 
@@ -437,7 +881,7 @@ impl<'a, 'b> Div<&'b BiggerInt> for &'a BiggerInt {
 	type Output = BiggerInt;
 	#[inline]
 	fn div(self, rhs: &'b BiggerInt) -> BiggerInt {
-		self.0.div(rhs.0).into()
+		*self / *rhs
 	}
 }
 
@@ -445,7 +889,12 @@ impl Rem<Self> for BiggerInt {
 	type Output = Self;
 	#[inline]
 	fn rem(self, rhs: Self) -> Self {
-		self.0.rem(rhs.0).into()
+		if let (BiggerInt::Small(a), BiggerInt::Small(b)) = (self, rhs) {
+			if let Some(v) = a.checked_rem(b) {
+				return BiggerInt::Small(v);
+			}
+		}
+		self.to_i512().rem(rhs.to_i512()).into()
 	}
 }
 
@@ -487,32 +936,134 @@ impl<'a> Product<&'a Self> for BiggerInt {
 
 impl Display for BiggerInt {
 	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-		self.0.fmt(f)
+		match self {
+			BiggerInt::Small(v) => v.fmt(f),
+			BiggerInt::Big(v) => v.fmt(f),
+		}
 	}
 }
 
-fn unsafe_u64_to_u8_slice(slice: &[u64]) -> &[u8] {
-	unsafe { std::slice::from_raw_parts(slice.as_ptr() as *const u8, std::mem::size_of_val(slice)) }
+/// Full 64-byte little-endian two's-complement form, computed without
+/// promoting `Small` values to an `I512` first.
+///
+/// Writes each `u64` limb's bytes out explicitly in little-endian order,
+/// rather than reinterpreting the limb array's native in-memory layout, so
+/// the encoding is identical regardless of host endianness.
+fn to_le_bytes_64(value: &BiggerInt) -> [u8; 64] {
+	match value {
+		BiggerInt::Small(v) => {
+			let mut bytes = [0u8; 64];
+			bytes[..16].copy_from_slice(&v.to_le_bytes());
+			let filler = if *v < 0 {
+				0xFF
+			} else {
+				0x00
+			};
+			for b in &mut bytes[16..] {
+				*b = filler;
+			}
+			bytes
+		}
+		BiggerInt::Big(v) => {
+			let limbs = v.to_bits();
+			let mut bytes = [0u8; 64];
+			for (limb, chunk) in limbs.digits().iter().zip(bytes.chunks_exact_mut(8)) {
+				chunk.copy_from_slice(&limb.to_le_bytes());
+			}
+			bytes
+		}
+	}
+}
+
+/// Length, in bytes, of the shortest prefix of `bytes` (a full 64-byte
+/// two's-complement little-endian form) that still sign-extends back to
+/// the original value. See [`revision` 2][Revisioned::revision] of
+/// `BiggerInt`.
+fn minimal_two_complement_len(bytes: &[u8; 64]) -> usize {
+	let negative = bytes[63] & 0x80 != 0;
+	let filler: u8 = if negative {
+		0xFF
+	} else {
+		0x00
+	};
+
+	let mut len = 64;
+	while len > 0 && bytes[len - 1] == filler {
+		len -= 1;
+	}
+
+	// An all-filler negative value (-1) must keep one byte: zero bytes
+	// always decode to positive zero.
+	if negative && len == 0 {
+		return 1;
+	}
+
+	// Keep one extra byte if dropping further would flip the sign of the
+	// new most-significant byte.
+	if len > 0 && (bytes[len - 1] & 0x80 != 0) != negative {
+		len += 1;
+	}
+
+	len
 }
 
 impl Revisioned for BiggerInt {
 	fn revision() -> u16 {
-		1
+		2
 	}
 	#[inline]
 	fn serialize_revisioned<W: std::io::Write>(&self, w: &mut W) -> Result<(), RevisionError> {
-		let limbs = self.0.to_bits();
-		let digits = limbs.digits();
-		let bytes = unsafe_u64_to_u8_slice(digits);
-		w.write_all(bytes).map_err(|e| RevisionError::Io(e.raw_os_error().unwrap_or(0)))
+		let bytes = to_le_bytes_64(self);
+
+		let len = minimal_two_complement_len(&bytes);
+		w.write_all(&[len as u8]).map_err(|e| RevisionError::Io(e.raw_os_error().unwrap_or(0)))?;
+		w.write_all(&bytes[..len]).map_err(|e| RevisionError::Io(e.raw_os_error().unwrap_or(0)))
 	}
 	#[inline]
 	fn deserialize_revisioned<R: std::io::Read>(r: &mut R) -> Result<Self, RevisionError> {
-		let mut v = [0u8; 64];
-		
-		r.read_exact(v.as_mut_slice())
-			.map_err(|e| RevisionError::Io(e.raw_os_error().unwrap()))?;
-		Ok(BiggerInt(I512::from_le_slice(&v).unwrap_or(I512::ZERO)))
+		let mut len_byte = [0u8; 1];
+		r.read_exact(&mut len_byte).map_err(|e| RevisionError::Io(e.raw_os_error().unwrap_or(0)))?;
+		let len = len_byte[0];
+		if len as usize > 64 {
+			return Err(RevisionError::Deserialize(format!("invalid BiggerInt length byte {len}")));
+		}
+
+		let mut bytes = [0u8; 64];
+		if len > 0 {
+			r.read_exact(&mut bytes[..len as usize])
+				.map_err(|e| RevisionError::Io(e.raw_os_error().unwrap_or(0)))?;
+			if bytes[len as usize - 1] & 0x80 != 0 {
+				for b in &mut bytes[len as usize..] {
+					*b = 0xFF;
+				}
+			}
+		}
+
+		let v = I512::from_le_slice(&bytes)
+			.ok_or_else(|| RevisionError::Deserialize("invalid BiggerInt byte length".to_owned()))?;
+		Ok(BiggerInt::from_i512(v))
+	}
+}
+
+impl BiggerInt {
+	/// Reads a `BiggerInt` stored in the fixed 64-byte format used by
+	/// [`Revisioned`] revision 1, before the compact length-prefixed
+	/// encoding introduced in revision 2.
+	///
+	/// A revision-1 payload carries no length prefix and is indistinguishable
+	/// from revision-2 bytes by content alone (a revision-1 low byte of, say,
+	/// `10` is a valid revision-2 length too) - it can only be told apart by
+	/// whichever revision the *container* around this value was written at.
+	/// Callers that need to read old data therefore call this directly, the
+	/// same way other fields in this codebase disambiguate historical
+	/// formats via `#[revision(start = .., end = .., convert_fn = "...")]`,
+	/// rather than `deserialize_revisioned` guessing from the bytes.
+	pub(crate) fn deserialize_revision_1<R: std::io::Read>(r: &mut R) -> Result<Self, RevisionError> {
+		let mut bytes = [0u8; 64];
+		r.read_exact(&mut bytes).map_err(|e| RevisionError::Io(e.raw_os_error().unwrap_or(0)))?;
+		let v = I512::from_le_slice(&bytes)
+			.ok_or_else(|| RevisionError::Deserialize("invalid BiggerInt byte length".to_owned()))?;
+		Ok(BiggerInt::from_i512(v))
 	}
 }
 
@@ -548,4 +1099,182 @@ mod tests {
 		let serialized = Serialize::serialize(&number, Serializer.wrap()).unwrap();
 		assert_eq!(number, serialized);
 	}
+
+	#[test]
+	fn small_big_canonicalize_across_the_i128_boundary() {
+		assert_eq!(BiggerInt::from(i128::MAX), BiggerInt::Small(i128::MAX));
+		let one_past = BiggerInt::from(i128::MAX).checked_add(BiggerInt::one()).unwrap();
+		assert!(matches!(one_past, BiggerInt::Big(_)));
+		let back = one_past.checked_sub(BiggerInt::one()).unwrap();
+		assert_eq!(back, BiggerInt::from(i128::MAX));
+		assert!(matches!(back, BiggerInt::Small(_)));
+	}
+
+	#[derive(Serialize, Deserialize)]
+	struct DecimalRepr(#[serde(with = "repr::decimal")] BiggerInt);
+
+	#[derive(Serialize, Deserialize)]
+	struct Prefixed(#[serde(with = "repr::prefixed")] BiggerInt);
+
+	#[derive(Serialize, Deserialize)]
+	struct Permissive(#[serde(with = "repr::permissive")] BiggerInt);
+
+	#[derive(Serialize, Deserialize)]
+	struct Le(#[serde(with = "repr::bytes::le")] BiggerInt);
+
+	#[derive(Serialize, Deserialize)]
+	struct Be(#[serde(with = "repr::bytes::be")] BiggerInt);
+
+	#[test]
+	fn repr_decimal_round_trips_negative() {
+		let number = DecimalRepr(BiggerInt::from(-42i64));
+		let json = serde_json::to_string(&number).unwrap();
+		assert_eq!(json, "\"-42\"");
+		let back: DecimalRepr = serde_json::from_str(&json).unwrap();
+		assert_eq!(back.0, number.0);
+	}
+
+	#[test]
+	fn repr_prefixed_accepts_hex_and_decimal() {
+		let number = Prefixed(BiggerInt::from(255i64));
+		let json = serde_json::to_string(&number).unwrap();
+		assert_eq!(json, "\"0xff\"");
+		let from_hex: Prefixed = serde_json::from_str(&json).unwrap();
+		assert_eq!(from_hex.0, number.0);
+		let from_decimal: Prefixed = serde_json::from_str("\"255\"").unwrap();
+		assert_eq!(from_decimal.0, number.0);
+	}
+
+	#[test]
+	fn repr_permissive_accepts_any_form() {
+		let expected = BiggerInt::from(-255i64);
+		let from_hex: Permissive = serde_json::from_str("\"-0xff\"").unwrap();
+		assert_eq!(from_hex.0, expected);
+		let from_decimal: Permissive = serde_json::from_str("\"-255\"").unwrap();
+		assert_eq!(from_decimal.0, expected);
+		let from_int: Permissive = serde_json::from_str("-255").unwrap();
+		assert_eq!(from_int.0, expected);
+	}
+
+	#[test]
+	fn repr_bytes_round_trip_le_and_be() {
+		for value in [BiggerInt::from(-1i64), BiggerInt::from(12345i64), BiggerInt::zero()] {
+			let le = serde_json::to_vec(&Le(value)).unwrap();
+			let back_le: Le = serde_json::from_slice(&le).unwrap();
+			assert_eq!(back_le.0, value);
+
+			let be = serde_json::to_vec(&Be(value)).unwrap();
+			let back_be: Be = serde_json::from_slice(&be).unwrap();
+			assert_eq!(back_be.0, value);
+		}
+	}
+
+	fn revisioned_round_trip(value: BiggerInt) -> Vec<u8> {
+		let mut buf = Vec::new();
+		value.serialize_revisioned(&mut buf).unwrap();
+		let back = BiggerInt::deserialize_revisioned(&mut &buf[..]).unwrap();
+		assert_eq!(back, value);
+		buf
+	}
+
+	#[test]
+	fn revisioned_v2_is_compact_for_small_values() {
+		assert_eq!(revisioned_round_trip(BiggerInt::zero()).len(), 1);
+		assert_eq!(revisioned_round_trip(BiggerInt::from(1i64)).len(), 2);
+		assert_eq!(revisioned_round_trip(BiggerInt::from(-1i64)).len(), 2);
+		assert_eq!(revisioned_round_trip(BiggerInt::from(128i64)).len(), 3);
+		revisioned_round_trip(BiggerInt::from(i128::MAX));
+		revisioned_round_trip(BiggerInt::from(i128::MIN));
+	}
+
+	#[test]
+	fn deserialize_revision_1_reads_legacy_fixed_payload() {
+		// A legacy payload with a low byte that happens to be a valid
+		// revision-2 length (<= 64) - the case revision-2 auto-detection
+		// used to decode incorrectly.
+		let value = BiggerInt::from(10i64);
+		let mut legacy = [0u8; 64];
+		legacy[0] = 10;
+		let back = BiggerInt::deserialize_revision_1(&mut &legacy[..]).unwrap();
+		assert_eq!(back, value);
+
+		let value = BiggerInt::from(-42i64);
+		let mut legacy = [0u8; 64];
+		legacy.fill(0xFF);
+		legacy[0] = 214; // -42 as a little-endian two's-complement byte
+		let back = BiggerInt::deserialize_revision_1(&mut &legacy[..]).unwrap();
+		assert_eq!(back, value);
+	}
+
+	#[test]
+	fn revisioned_v2_rejects_out_of_range_length() {
+		let bytes = [65u8];
+		assert!(BiggerInt::deserialize_revisioned(&mut &bytes[..]).is_err());
+	}
+
+	#[test]
+	fn try_from_f64_accepts_integral_values() {
+		assert_eq!(BiggerInt::try_from(42.0).unwrap(), BiggerInt::from(42i64));
+		assert_eq!(BiggerInt::try_from(-42.0).unwrap(), BiggerInt::from(-42i64));
+		assert_eq!(BiggerInt::try_from(0.0).unwrap(), BiggerInt::zero());
+		// 2^100 is exactly representable as an f64, and exceeds u64.
+		let huge = 2f64.powi(100);
+		assert_eq!(BiggerInt::try_from(huge).unwrap(), BiggerInt::from_i512(I512::from(2i32).pow(100)));
+	}
+
+	#[test]
+	fn try_from_f64_rejects_non_integral_and_non_finite() {
+		assert!(BiggerInt::try_from(1.5).is_err());
+		assert!(BiggerInt::try_from(f64::NAN).is_err());
+		assert!(BiggerInt::try_from(f64::INFINITY).is_err());
+		assert!(BiggerInt::try_from(f64::NEG_INFINITY).is_err());
+	}
+
+	#[test]
+	fn try_from_f64_rejects_values_that_overflow_i512() {
+		// Finite, integral, but its true magnitude needs 521 bits - past
+		// what a signed I512 can represent.
+		assert!(BiggerInt::try_from(2f64.powi(520)).is_err());
+		assert!(BiggerInt::try_from(-(2f64.powi(520))).is_err());
+	}
+
+	#[test]
+	fn try_from_decimal_exact_division() {
+		let value = Decimal::new(12345, 2); // 123.45
+		assert!(BiggerInt::try_from(value).is_err());
+		let whole = Decimal::new(12300, 2); // 123.00
+		assert_eq!(BiggerInt::try_from(whole).unwrap(), BiggerInt::from(123i64));
+	}
+
+	#[test]
+	fn to_f64_round_trips_and_saturates() {
+		assert_eq!(BiggerInt::from(-42i64).to_f64(), Some(-42.0));
+		let huge = BiggerInt::from_i512(I512::from(2i32).pow(100));
+		assert_eq!(huge.to_f64(), Some(2f64.powi(100)));
+		assert_eq!(huge.to_f32(), Some(2f64.powi(100) as f32));
+
+		let overflowing = BiggerInt::from_i512(I512::MAX);
+		assert_eq!(overflowing.to_f32(), Some(f32::INFINITY));
+	}
+
+	#[test]
+	fn revisioned_round_trips_negative_values() {
+		revisioned_round_trip(BiggerInt::from(-1i64));
+		revisioned_round_trip(BiggerInt::from(-42i64));
+		revisioned_round_trip(BiggerInt::from_i512(-I512::from(2i32).pow(100)));
+	}
+
+	#[test]
+	fn revisioned_round_trips_i512_bounds() {
+		revisioned_round_trip(BiggerInt::from_i512(I512::MIN));
+		revisioned_round_trip(BiggerInt::from_i512(I512::MAX));
+	}
+
+	#[test]
+	fn revisioned_round_trips_i128_u128_boundaries() {
+		revisioned_round_trip(BiggerInt::from(i128::MIN));
+		revisioned_round_trip(BiggerInt::from(i128::MAX));
+		revisioned_round_trip(BiggerInt::from(u128::MAX));
+		revisioned_round_trip(BiggerInt::from_i512(i128_to_i512(i128::MAX) + I512::ONE));
+	}
 }